@@ -0,0 +1,49 @@
+use crate::c_api::types::{BoundedSampleStatus, Csprng, CsprngVtable};
+use crate::implementation::bounded_uniform::{bounded_u32, bounded_u64};
+use crate::implementation::types::CsprngMut;
+
+/// Draws a uniform integer in `[0, bound)` without modulo bias, writing it to `*result`.
+///
+/// # Safety
+///
+/// `csprng` must be a valid pointer, and a valid receiver for every function in `vtable`.
+/// `result` must be a valid pointer to a `u32`.
+#[no_mangle]
+pub unsafe extern "C" fn concrete_cpu_csprng_bounded_u32(
+    csprng: *mut Csprng,
+    vtable: CsprngVtable,
+    bound: u32,
+    result: *mut u32,
+) -> BoundedSampleStatus {
+    let mut csprng = CsprngMut::new(csprng, &vtable);
+    match bounded_u32(&mut csprng, bound) {
+        Ok(value) => {
+            *result = value;
+            BoundedSampleStatus::Valid
+        }
+        Err(_) => BoundedSampleStatus::ZeroBound,
+    }
+}
+
+/// Draws a uniform integer in `[0, bound)` without modulo bias, writing it to `*result`.
+///
+/// # Safety
+///
+/// `csprng` must be a valid pointer, and a valid receiver for every function in `vtable`.
+/// `result` must be a valid pointer to a `u64`.
+#[no_mangle]
+pub unsafe extern "C" fn concrete_cpu_csprng_bounded_u64(
+    csprng: *mut Csprng,
+    vtable: CsprngVtable,
+    bound: u64,
+    result: *mut u64,
+) -> BoundedSampleStatus {
+    let mut csprng = CsprngMut::new(csprng, &vtable);
+    match bounded_u64(&mut csprng, bound) {
+        Ok(value) => {
+            *result = value;
+            BoundedSampleStatus::Valid
+        }
+        Err(_) => BoundedSampleStatus::ZeroBound,
+    }
+}
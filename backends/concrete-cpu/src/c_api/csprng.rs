@@ -0,0 +1,230 @@
+use crate::c_api::types::{Csprng, CsprngVtable, Uint128};
+use crate::implementation::buffered_csprng::BufferedCsprng;
+use crate::implementation::forkable_csprng::ForkableCsprng;
+use concrete_csprng::generators::{RandomGenerator, SoftwareRandomGenerator};
+
+unsafe extern "C" fn software_remaining_bytes(csprng: *const Csprng) -> Uint128 {
+    let generator = &*(csprng as *const SoftwareRandomGenerator);
+    let count = generator.remaining_bytes().0;
+    Uint128 {
+        little_endian_bytes: count.to_le_bytes(),
+    }
+}
+
+unsafe extern "C" fn software_next_bytes(
+    csprng: *mut Csprng,
+    byte_array: *mut u8,
+    byte_count: usize,
+) -> usize {
+    let generator = &mut *(csprng as *mut SoftwareRandomGenerator);
+    let out = core::slice::from_raw_parts_mut(byte_array, byte_count);
+    for byte in out.iter_mut() {
+        *byte = generator
+            .next_byte()
+            .expect("the software csprng is never exhausted");
+    }
+    byte_count
+}
+
+/// The vtable driving a [`SoftwareRandomGenerator`], i.e. the deterministic, seed-derived
+/// generator used when reproducibility matters more than entropy quality (tests, simulation).
+pub static CONCRETE_CSPRNG_VTABLE: CsprngVtable = CsprngVtable {
+    remaining_bytes: software_remaining_bytes,
+    next_bytes: software_next_bytes,
+};
+
+#[cfg(any(target_os = "linux", target_os = "android"))]
+mod os_random {
+    use std::sync::atomic::{AtomicBool, Ordering};
+
+    // Once `getrandom` has told us it can't serve requests (old kernel, or entropy pool not
+    // seeded yet and caller refuses to block), stop paying the syscall tax and go straight to
+    // `/dev/urandom` for the rest of the process's life.
+    static FALLBACK_TO_DEV_URANDOM: AtomicBool = AtomicBool::new(false);
+
+    /// Attempts to fill `buf` using the `getrandom` syscall. Returns `true` on success, or
+    /// `false` if the caller should fall back to reading `/dev/urandom` instead.
+    pub fn fill(buf: &mut [u8]) -> bool {
+        if FALLBACK_TO_DEV_URANDOM.load(Ordering::Relaxed) {
+            return false;
+        }
+
+        let mut filled = 0;
+        while filled < buf.len() {
+            let ret = unsafe {
+                libc::syscall(
+                    libc::SYS_getrandom,
+                    buf[filled..].as_mut_ptr(),
+                    buf.len() - filled,
+                    libc::GRND_NONBLOCK,
+                )
+            };
+
+            if ret < 0 {
+                let errno = std::io::Error::last_os_error()
+                    .raw_os_error()
+                    .unwrap_or(0);
+                match errno {
+                    libc::EINTR => continue,
+                    libc::ENOSYS | libc::EAGAIN => {
+                        FALLBACK_TO_DEV_URANDOM.store(true, Ordering::Relaxed);
+                        return false;
+                    }
+                    _ => panic!("getrandom failed with errno {errno}"),
+                }
+            }
+
+            filled += ret as usize;
+        }
+
+        true
+    }
+}
+
+mod dev_urandom {
+    use std::fs::File;
+    use std::io::Read;
+    use std::sync::{Mutex, MutexGuard, OnceLock};
+
+    static FILE: OnceLock<Mutex<File>> = OnceLock::new();
+
+    fn file() -> MutexGuard<'static, File> {
+        FILE.get_or_init(|| {
+            Mutex::new(File::open("/dev/urandom").expect("failed to open /dev/urandom"))
+        })
+        .lock()
+        .unwrap()
+    }
+
+    /// Fills `buf` by reading `/dev/urandom` in a loop until `buf` is full, retrying on
+    /// interrupted reads.
+    pub fn fill(buf: &mut [u8]) {
+        let mut file = file();
+        let mut filled = 0;
+        while filled < buf.len() {
+            match file.read(&mut buf[filled..]) {
+                Ok(0) => panic!("/dev/urandom returned EOF"),
+                Ok(n) => filled += n,
+                Err(e) if e.kind() == std::io::ErrorKind::Interrupted => continue,
+                Err(e) => panic!("failed to read from /dev/urandom: {e}"),
+            }
+        }
+    }
+}
+
+unsafe extern "C" fn os_random_remaining_bytes(_csprng: *const Csprng) -> Uint128 {
+    // The OS pool is never considered exhausted: report the maximum representable count.
+    Uint128 {
+        little_endian_bytes: [0xff; 16],
+    }
+}
+
+unsafe extern "C" fn os_random_next_bytes(
+    _csprng: *mut Csprng,
+    byte_array: *mut u8,
+    byte_count: usize,
+) -> usize {
+    let buf = core::slice::from_raw_parts_mut(byte_array, byte_count);
+
+    #[cfg(any(target_os = "linux", target_os = "android"))]
+    {
+        if !os_random::fill(buf) {
+            dev_urandom::fill(buf);
+        }
+    }
+
+    #[cfg(not(any(target_os = "linux", target_os = "android")))]
+    {
+        dev_urandom::fill(buf);
+    }
+
+    byte_count
+}
+
+/// The vtable for an OS-entropy-backed generator: no seed to manage, no state to carry, it reads
+/// directly from the kernel CSPRNG (`getrandom` on Linux/Android, falling back to and otherwise
+/// using `/dev/urandom`) on every call.
+pub static CONCRETE_CSPRNG_VTABLE_OS_RANDOM: CsprngVtable = CsprngVtable {
+    remaining_bytes: os_random_remaining_bytes,
+    next_bytes: os_random_next_bytes,
+};
+
+unsafe extern "C" fn buffered_remaining_bytes(csprng: *const Csprng) -> Uint128 {
+    let buffered = &*(csprng as *const BufferedCsprng);
+    buffered.remaining_bytes()
+}
+
+unsafe extern "C" fn buffered_next_bytes(
+    csprng: *mut Csprng,
+    byte_array: *mut u8,
+    byte_count: usize,
+) -> usize {
+    let buffered = &mut *(csprng as *mut BufferedCsprng);
+    let out = core::slice::from_raw_parts_mut(byte_array, byte_count);
+    buffered.next_bytes(out)
+}
+
+/// The vtable for a [`BufferedCsprng`], letting it compose with any other generator exposed
+/// through this module: wrap the software vtable, the OS-random vtable, or a forked child, and
+/// drive the result through the usual `Csprng`/`CsprngVtable` pair.
+pub static CONCRETE_CSPRNG_VTABLE_BUFFERED: CsprngVtable = CsprngVtable {
+    remaining_bytes: buffered_remaining_bytes,
+    next_bytes: buffered_next_bytes,
+};
+
+unsafe extern "C" fn forkable_remaining_bytes(csprng: *const Csprng) -> Uint128 {
+    let forkable = &*(csprng as *const ForkableCsprng);
+    forkable.remaining_bytes()
+}
+
+unsafe extern "C" fn forkable_next_bytes(
+    csprng: *mut Csprng,
+    byte_array: *mut u8,
+    byte_count: usize,
+) -> usize {
+    let forkable = &mut *(csprng as *mut ForkableCsprng);
+    let out = core::slice::from_raw_parts_mut(byte_array, byte_count);
+    forkable.next_bytes(out)
+}
+
+/// The vtable for a [`ForkableCsprng`], used for `Parallelism::Rayon` key/noise generation where
+/// each task draws from its own forked child stream.
+pub static CONCRETE_CSPRNG_VTABLE_FORKABLE: CsprngVtable = CsprngVtable {
+    remaining_bytes: forkable_remaining_bytes,
+    next_bytes: forkable_next_bytes,
+};
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::implementation::types::CsprngMut;
+
+    fn os_random_mut<'a>() -> CsprngMut<'a, 'static> {
+        unsafe { CsprngMut::new(core::ptr::null_mut(), &CONCRETE_CSPRNG_VTABLE_OS_RANDOM) }
+    }
+
+    #[test]
+    fn os_random_fills_requested_byte_count() {
+        let mut csprng = os_random_mut();
+        let mut buf = [0u8; 4096];
+        let written = csprng.next_bytes(&mut buf);
+        assert_eq!(written, buf.len());
+    }
+
+    #[test]
+    fn os_random_does_not_report_exhaustion() {
+        let csprng = os_random_mut();
+        let remaining = u128::from_le_bytes(csprng.remaining_bytes().little_endian_bytes);
+        assert_eq!(remaining, u128::MAX);
+    }
+
+    #[test]
+    fn os_random_draws_are_not_constant() {
+        let mut csprng = os_random_mut();
+        let mut a = [0u8; 64];
+        let mut b = [0u8; 64];
+        csprng.next_bytes(&mut a);
+        csprng.next_bytes(&mut b);
+        assert_ne!(a, b);
+    }
+}
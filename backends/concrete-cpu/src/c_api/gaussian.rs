@@ -0,0 +1,35 @@
+use crate::c_api::types::{Csprng, CsprngVtable};
+use crate::implementation::gaussian::{gaussian_f64, gaussian_i64};
+use crate::implementation::types::CsprngMut;
+
+/// Draws one `f64` sample from `N(0, sigma * sigma)`, using `csprng` (driven through `vtable`)
+/// as the source of randomness.
+///
+/// # Safety
+///
+/// `csprng` must be a valid pointer, and a valid receiver for every function in `vtable`.
+#[no_mangle]
+pub unsafe extern "C" fn concrete_cpu_csprng_gaussian_f64(
+    csprng: *mut Csprng,
+    vtable: CsprngVtable,
+    sigma: f64,
+) -> f64 {
+    let mut csprng = CsprngMut::new(csprng, &vtable);
+    gaussian_f64(&mut csprng, sigma)
+}
+
+/// Draws one sample from `N(0, sigma * sigma)` rounded to the nearest integer, for LWE-style
+/// discretized noise.
+///
+/// # Safety
+///
+/// `csprng` must be a valid pointer, and a valid receiver for every function in `vtable`.
+#[no_mangle]
+pub unsafe extern "C" fn concrete_cpu_csprng_gaussian_i64(
+    csprng: *mut Csprng,
+    vtable: CsprngVtable,
+    sigma: f64,
+) -> i64 {
+    let mut csprng = CsprngMut::new(csprng, &vtable);
+    gaussian_i64(&mut csprng, sigma)
+}
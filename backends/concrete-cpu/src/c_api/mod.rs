@@ -0,0 +1,4 @@
+pub mod bounded_uniform;
+pub mod csprng;
+pub mod gaussian;
+pub mod types;
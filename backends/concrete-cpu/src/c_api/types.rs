@@ -41,6 +41,13 @@ pub enum Parallelism {
     Rayon = 1,
 }
 
+#[repr(u32)]
+#[derive(Copy, Clone, Debug)]
+pub enum BoundedSampleStatus {
+    Valid = 0,
+    ZeroBound = 1,
+}
+
 #[cfg(test)]
 pub mod tests {
     use super::*;
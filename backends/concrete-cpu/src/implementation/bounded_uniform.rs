@@ -0,0 +1,127 @@
+use crate::implementation::types::CsprngMut;
+
+/// Returned by the bounded sampling helpers when asked to sample from an empty range.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct ZeroBoundError;
+
+fn draw_u32(csprng: &mut CsprngMut<'_, '_>) -> u32 {
+    let mut bytes = [0u8; 4];
+    csprng.next_bytes(&mut bytes);
+    u32::from_le_bytes(bytes)
+}
+
+fn draw_u64(csprng: &mut CsprngMut<'_, '_>) -> u64 {
+    let mut bytes = [0u8; 8];
+    csprng.next_bytes(&mut bytes);
+    u64::from_le_bytes(bytes)
+}
+
+/// Draws a uniform integer in `[0, bound)` without modulo bias, using Lemire's nearly
+/// divisionless method.
+pub fn bounded_u32(csprng: &mut CsprngMut<'_, '_>, bound: u32) -> Result<u32, ZeroBoundError> {
+    if bound == 0 {
+        return Err(ZeroBoundError);
+    }
+    if bound.is_power_of_two() {
+        return Ok(draw_u32(csprng) & (bound - 1));
+    }
+
+    let mut x = draw_u32(csprng);
+    let mut product = (x as u64) * (bound as u64);
+    let mut low = product as u32;
+    if low < bound {
+        let threshold = bound.wrapping_neg() % bound;
+        while low < threshold {
+            x = draw_u32(csprng);
+            product = (x as u64) * (bound as u64);
+            low = product as u32;
+        }
+    }
+    Ok((product >> 32) as u32)
+}
+
+/// Draws a uniform integer in `[0, bound)` without modulo bias, using Lemire's nearly
+/// divisionless method.
+pub fn bounded_u64(csprng: &mut CsprngMut<'_, '_>, bound: u64) -> Result<u64, ZeroBoundError> {
+    if bound == 0 {
+        return Err(ZeroBoundError);
+    }
+    if bound.is_power_of_two() {
+        return Ok(draw_u64(csprng) & (bound - 1));
+    }
+
+    let mut x = draw_u64(csprng);
+    let mut product = (x as u128) * (bound as u128);
+    let mut low = product as u64;
+    if low < bound {
+        let threshold = bound.wrapping_neg() % bound;
+        while low < threshold {
+            x = draw_u64(csprng);
+            product = (x as u128) * (bound as u128);
+            low = product as u64;
+        }
+    }
+    Ok((product >> 64) as u64)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::c_api::types::tests::to_generic;
+    use concrete_csprng::generators::SoftwareRandomGenerator;
+    use concrete_csprng::seeders::Seed;
+
+    #[test]
+    fn zero_bound_is_rejected() {
+        let mut generator = SoftwareRandomGenerator::new(Seed(0));
+        let mut csprng = to_generic(&mut generator);
+        assert_eq!(bounded_u64(&mut csprng, 0), Err(ZeroBoundError));
+        assert_eq!(bounded_u32(&mut csprng, 0), Err(ZeroBoundError));
+    }
+
+    #[test]
+    fn draws_always_fall_within_bound() {
+        let mut generator = SoftwareRandomGenerator::new(Seed(1));
+        let mut csprng = to_generic(&mut generator);
+        for bound in [1u64, 2, 3, 5, 7, 17, 64, 1_000_003] {
+            for _ in 0..1_000 {
+                let value = bounded_u64(&mut csprng, bound).unwrap();
+                assert!(value < bound);
+            }
+        }
+    }
+
+    /// Chi-squared goodness-of-fit test against the uniform distribution, for a handful of
+    /// small, non-power-of-two bounds where bias would be easiest to introduce by accident.
+    #[test]
+    fn draws_are_uniform_by_chi_squared_test() {
+        // 99.9th percentile chi-squared critical values for (bound - 1) degrees of freedom.
+        let cases: &[(u64, f64)] = &[(3, 13.816), (5, 18.467), (6, 20.515), (10, 27.877)];
+
+        let mut generator = SoftwareRandomGenerator::new(Seed(2));
+        let mut csprng = to_generic(&mut generator);
+
+        for &(bound, critical_value) in cases {
+            const SAMPLES: u64 = 100_000;
+            let mut counts = vec![0u64; bound as usize];
+            for _ in 0..SAMPLES {
+                let value = bounded_u64(&mut csprng, bound).unwrap();
+                counts[value as usize] += 1;
+            }
+
+            let expected = SAMPLES as f64 / bound as f64;
+            let chi_squared: f64 = counts
+                .iter()
+                .map(|&count| {
+                    let diff = count as f64 - expected;
+                    diff * diff / expected
+                })
+                .sum();
+
+            assert!(
+                chi_squared < critical_value,
+                "bound {bound}: chi-squared {chi_squared} exceeded critical value {critical_value}"
+            );
+        }
+    }
+}
@@ -0,0 +1,133 @@
+use crate::c_api::types::{Csprng, CsprngVtable, Uint128};
+use crate::implementation::types::CsprngMut;
+
+const BUFFER_SIZE: usize = 256;
+
+/// An adaptor that amortizes the per-call cost of `next_bytes` by drawing from an inner
+/// generator in fixed-size blocks and serving small requests out of a local buffer.
+///
+/// This matters most for block-cipher-based generators (e.g. ChaCha), where each call to the
+/// underlying `next_bytes` forces a fresh block even if the caller only asked for a handful of
+/// bytes: buffering lets several small draws share one block.
+pub struct BufferedCsprng {
+    inner: *mut Csprng,
+    inner_vtable: *const CsprngVtable,
+    buffer: [u8; BUFFER_SIZE],
+    cursor: usize,
+    filled: usize,
+}
+
+impl BufferedCsprng {
+    /// Wraps `inner` in a buffered adaptor. The wrapped generator's lifetime is not tracked past
+    /// this call: callers are responsible for keeping it alive for as long as the resulting
+    /// `BufferedCsprng` is used, exactly as with the raw `Csprng`/`CsprngVtable` pair.
+    pub fn new(inner: CsprngMut<'_, '_>) -> Self {
+        let (inner, inner_vtable) = inner.into_raw_parts();
+        Self {
+            inner,
+            inner_vtable,
+            buffer: [0; BUFFER_SIZE],
+            cursor: 0,
+            filled: 0,
+        }
+    }
+
+    fn inner_vtable(&self) -> &CsprngVtable {
+        unsafe { &*self.inner_vtable }
+    }
+
+    fn refill(&mut self) {
+        self.filled =
+            unsafe { (self.inner_vtable().next_bytes)(self.inner, self.buffer.as_mut_ptr(), BUFFER_SIZE) };
+        self.cursor = 0;
+    }
+
+    /// Fills `bytes` with random data, returning the number of bytes actually written. Requests
+    /// larger than the internal buffer drain whatever is already buffered, then bypass it and
+    /// go straight to the inner generator for the remainder.
+    pub fn next_bytes(&mut self, bytes: &mut [u8]) -> usize {
+        if bytes.len() > BUFFER_SIZE {
+            let buffered = self.filled - self.cursor;
+            bytes[..buffered].copy_from_slice(&self.buffer[self.cursor..self.filled]);
+            self.cursor = self.filled;
+
+            let written = unsafe {
+                (self.inner_vtable().next_bytes)(
+                    self.inner,
+                    bytes[buffered..].as_mut_ptr(),
+                    bytes.len() - buffered,
+                )
+            };
+            return buffered + written;
+        }
+
+        let mut written = 0;
+        while written < bytes.len() {
+            if self.cursor == self.filled {
+                self.refill();
+                if self.filled == 0 {
+                    // The inner generator is exhausted: stop, reporting what we managed.
+                    break;
+                }
+            }
+
+            let available = self.filled - self.cursor;
+            let to_copy = available.min(bytes.len() - written);
+            bytes[written..written + to_copy]
+                .copy_from_slice(&self.buffer[self.cursor..self.cursor + to_copy]);
+            self.cursor += to_copy;
+            written += to_copy;
+        }
+
+        written
+    }
+
+    /// Returns the buffered leftover plus the inner generator's own remaining count.
+    pub fn remaining_bytes(&self) -> Uint128 {
+        let buffered = (self.filled - self.cursor) as u128;
+        let source = u128::from_le_bytes(
+            unsafe { (self.inner_vtable().remaining_bytes)(self.inner) }.little_endian_bytes,
+        );
+        Uint128 {
+            little_endian_bytes: buffered.saturating_add(source).to_le_bytes(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::c_api::types::tests::to_generic;
+    use concrete_csprng::generators::SoftwareRandomGenerator;
+    use concrete_csprng::seeders::Seed;
+
+    fn generator() -> SoftwareRandomGenerator {
+        SoftwareRandomGenerator::new(Seed(0xdead_beef))
+    }
+
+    #[test]
+    fn buffered_matches_unbuffered_for_arbitrary_request_sizes() {
+        let request_sizes = [1usize, 1, 3, 7, 250, 2, 300, 256, 1, 500, 4, 4];
+
+        let mut reference_generator = generator();
+        let mut reference = unsafe {
+            crate::implementation::types::CsprngMut::new(
+                &mut reference_generator as *mut SoftwareRandomGenerator as *mut Csprng,
+                &crate::c_api::csprng::CONCRETE_CSPRNG_VTABLE,
+            )
+        };
+
+        let mut buffered_generator = generator();
+        let mut buffered =
+            BufferedCsprng::new(to_generic(&mut buffered_generator));
+
+        for &size in &request_sizes {
+            let mut expected = vec![0u8; size];
+            let mut actual = vec![0u8; size];
+
+            assert_eq!(reference.next_bytes(&mut expected), size);
+            assert_eq!(buffered.next_bytes(&mut actual), size);
+            assert_eq!(expected, actual);
+        }
+    }
+}
@@ -0,0 +1,213 @@
+use crate::c_api::types::Uint128;
+
+const CONSTANTS: [u32; 4] = [0x6170_7865, 0x3320_646e, 0x7962_2d32, 0x6b20_6574];
+const BLOCK_SIZE: usize = 64;
+
+#[inline]
+fn quarter_round(state: &mut [u32; 16], a: usize, b: usize, c: usize, d: usize) {
+    state[a] = state[a].wrapping_add(state[b]);
+    state[d] ^= state[a];
+    state[d] = state[d].rotate_left(16);
+
+    state[c] = state[c].wrapping_add(state[d]);
+    state[b] ^= state[c];
+    state[b] = state[b].rotate_left(12);
+
+    state[a] = state[a].wrapping_add(state[b]);
+    state[d] ^= state[a];
+    state[d] = state[d].rotate_left(8);
+
+    state[c] = state[c].wrapping_add(state[d]);
+    state[b] ^= state[c];
+    state[b] = state[b].rotate_left(7);
+}
+
+/// Computes one 64-byte ChaCha20 keystream block for the given key, 64-bit block counter, and
+/// 64-bit nonce.
+fn block(key: &[u32; 8], counter: u64, nonce: u64) -> [u8; BLOCK_SIZE] {
+    let mut state = [0u32; 16];
+    state[0..4].copy_from_slice(&CONSTANTS);
+    state[4..12].copy_from_slice(key);
+    state[12] = counter as u32;
+    state[13] = (counter >> 32) as u32;
+    state[14] = nonce as u32;
+    state[15] = (nonce >> 32) as u32;
+
+    let initial = state;
+    for _ in 0..10 {
+        quarter_round(&mut state, 0, 4, 8, 12);
+        quarter_round(&mut state, 1, 5, 9, 13);
+        quarter_round(&mut state, 2, 6, 10, 14);
+        quarter_round(&mut state, 3, 7, 11, 15);
+        quarter_round(&mut state, 0, 5, 10, 15);
+        quarter_round(&mut state, 1, 6, 11, 12);
+        quarter_round(&mut state, 2, 7, 8, 13);
+        quarter_round(&mut state, 3, 4, 9, 14);
+    }
+
+    let mut out = [0u8; BLOCK_SIZE];
+    for i in 0..16 {
+        let word = state[i].wrapping_add(initial[i]);
+        out[i * 4..i * 4 + 4].copy_from_slice(&word.to_le_bytes());
+    }
+    out
+}
+
+/// Nonce reserved for deriving child keys in [`ForkableCsprng::fork`]. No generator ever uses
+/// this nonce for its own keystream (every generator, root or forked, is keyed to nonce `0`), so
+/// a derivation call can never collide with a real `next_bytes` draw, from this generator or any
+/// other.
+const KEY_DERIVATION_NONCE: u64 = u64::MAX;
+
+/// A ChaCha20-keystream generator that can be deterministically split into independent child
+/// streams, so that parallel draws (e.g. one per Rayon task) are bit-reproducible regardless of
+/// thread scheduling.
+///
+/// Every generator derived from the same root key and fork path produces the same bytes no
+/// matter which thread runs it or in what order its siblings are drawn from. Forking re-keys
+/// each child from the parent's full 256-bit key (using the parent key as a ChaCha20-based PRF,
+/// keyed to the reserved [`KEY_DERIVATION_NONCE`]) rather than just perturbing a nonce field, so
+/// siblings — and every generator produced by any subsequent fork of any of them — are
+/// statistically independent regardless of fork depth or path.
+pub struct ForkableCsprng {
+    key: [u32; 8],
+    counter: u64,
+    buffer: [u8; BLOCK_SIZE],
+    buffer_filled: usize,
+    buffer_cursor: usize,
+}
+
+impl ForkableCsprng {
+    /// Creates a root generator from a 256-bit key.
+    pub fn new(key: [u32; 8]) -> Self {
+        Self {
+            key,
+            counter: 0,
+            buffer: [0; BLOCK_SIZE],
+            buffer_filled: 0,
+            buffer_cursor: 0,
+        }
+    }
+
+    /// Splits this generator into `n` independent children.
+    ///
+    /// Child `i`'s key is derived by running this generator's key through ChaCha20 as a PRF,
+    /// keyed to block index `i` under the reserved [`KEY_DERIVATION_NONCE`]. Because the
+    /// derivation consumes the entire parent key rather than one field of it, a child's own
+    /// descendants are independent of every other generator in the fork tree, not just its
+    /// immediate siblings.
+    pub fn fork(&self, n: usize) -> Vec<ForkableCsprng> {
+        (0..n)
+            .map(|i| {
+                let derived = block(&self.key, i as u64, KEY_DERIVATION_NONCE);
+                let mut child_key = [0u32; 8];
+                for (word, chunk) in child_key.iter_mut().zip(derived.chunks_exact(4)) {
+                    *word = u32::from_le_bytes(chunk.try_into().unwrap());
+                }
+                Self::new(child_key)
+            })
+            .collect()
+    }
+
+    fn refill(&mut self) {
+        self.buffer = block(&self.key, self.counter, 0);
+        self.counter = self.counter.wrapping_add(1);
+        self.buffer_cursor = 0;
+        self.buffer_filled = BLOCK_SIZE;
+    }
+
+    /// Fills `bytes` with keystream data, returning the number of bytes actually written.
+    pub fn next_bytes(&mut self, bytes: &mut [u8]) -> usize {
+        let mut written = 0;
+        while written < bytes.len() {
+            if self.buffer_cursor == self.buffer_filled {
+                self.refill();
+            }
+            let available = self.buffer_filled - self.buffer_cursor;
+            let to_copy = available.min(bytes.len() - written);
+            bytes[written..written + to_copy].copy_from_slice(
+                &self.buffer[self.buffer_cursor..self.buffer_cursor + to_copy],
+            );
+            self.buffer_cursor += to_copy;
+            written += to_copy;
+        }
+        written
+    }
+
+    /// Returns the number of bytes this stream can still produce before its 64-bit block
+    /// counter would wrap and repeat its own keystream.
+    pub fn remaining_bytes(&self) -> Uint128 {
+        let buffered = (self.buffer_filled - self.buffer_cursor) as u128;
+        let blocks_remaining = (1u128 << 64) - self.counter as u128;
+        let total = buffered + blocks_remaining * (BLOCK_SIZE as u128);
+        Uint128 {
+            little_endian_bytes: total.to_le_bytes(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rayon::prelude::*;
+
+    fn key() -> [u32; 8] {
+        [1, 2, 3, 4, 5, 6, 7, 8]
+    }
+
+    #[test]
+    fn forked_children_have_distinct_keys_from_parent_and_each_other() {
+        let parent = ForkableCsprng::new(key());
+        let children = parent.fork(4);
+        let keys: Vec<[u32; 8]> = children.iter().map(|c| c.key).collect();
+        assert!(keys.iter().all(|k| *k != parent.key));
+        for (i, a) in keys.iter().enumerate() {
+            for b in &keys[i + 1..] {
+                assert_ne!(a, b);
+            }
+        }
+    }
+
+    #[test]
+    fn nested_fork_does_not_reproduce_a_root_level_sibling() {
+        let root = ForkableCsprng::new(key());
+        let root_children = root.fork(2);
+        let nested_children = root_children[0].fork(2);
+
+        for nested in &nested_children {
+            for sibling in &root_children {
+                assert_ne!(nested.key, sibling.key);
+            }
+        }
+    }
+
+    #[test]
+    fn fork_is_deterministic_and_order_independent_under_parallel_draws() {
+        const N_CHILDREN: usize = 8;
+        const DRAWS_PER_CHILD: usize = 64;
+
+        let reference: Vec<Vec<u8>> = ForkableCsprng::new(key())
+            .fork(N_CHILDREN)
+            .iter_mut()
+            .map(|child| {
+                let mut out = vec![0u8; DRAWS_PER_CHILD];
+                child.next_bytes(&mut out);
+                out
+            })
+            .collect();
+
+        // Interleave the same draws across Rayon tasks: scheduling order must not change the
+        // bytes produced by any single child.
+        let parallel: Vec<Vec<u8>> = ForkableCsprng::new(key())
+            .fork(N_CHILDREN)
+            .into_par_iter()
+            .map(|mut child| {
+                let mut out = vec![0u8; DRAWS_PER_CHILD];
+                child.next_bytes(&mut out);
+                out
+            })
+            .collect();
+
+        assert_eq!(reference, parallel);
+    }
+}
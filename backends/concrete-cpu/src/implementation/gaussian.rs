@@ -0,0 +1,280 @@
+use crate::implementation::types::CsprngMut;
+use std::sync::OnceLock;
+
+/// Number of ziggurat layers. Layer `0` is the base strip, which covers the exponential tail.
+const LAYERS: usize = 256;
+
+#[inline]
+fn kernel(x: f64) -> f64 {
+    (-0.5 * x * x).exp()
+}
+
+/// `erfc` via the Chebyshev-fitted approximation of Numerical Recipes' `erfcc` (fractional
+/// error below 1.2e-7),
+/// used only once, at table-construction time, to size the tail strip.
+fn erfc(x: f64) -> f64 {
+    let z = x.abs();
+    let t = 1.0 / (1.0 + 0.5 * z);
+    #[allow(clippy::excessive_precision)]
+    let tau = t
+        * (-z * z - 1.26551223
+            + t * (1.00002368
+                + t * (0.37409196
+                    + t * (0.09678418
+                        + t * (-0.18628806
+                            + t * (0.27886807
+                                + t * (-1.13520398
+                                    + t * (1.48851587 + t * (-0.82215223 + t * 0.17087277)))))))))
+        .exp();
+    if x >= 0.0 {
+        tau
+    } else {
+        2.0 - tau
+    }
+}
+
+/// Area under the standard-normal kernel to the right of `r`.
+fn tail_area(r: f64) -> f64 {
+    (core::f64::consts::PI / 2.0).sqrt() * erfc(r / core::f64::consts::SQRT_2)
+}
+
+/// The shared rectangle area implied by cutting the tail at `r`.
+fn common_area(r: f64) -> f64 {
+    r * kernel(r) + tail_area(r)
+}
+
+/// Builds the layer boundaries implied by cutting the tail at `r`: `chain[LAYERS]` is `r` itself,
+/// and each `chain[i - 1]` is derived from `chain[i]` so that layer `i` has area `common_area(r)`.
+/// `chain[0]` is left at `0.0` (the peak).
+///
+/// Near the true cut-off, the last couple of steps push `f_i + area / x[i + 1]` to within a few
+/// ULPs of `1.0` from above, which would otherwise send a negative argument into `sqrt` and poison
+/// the whole chain with `NaN`; the argument is clamped to `1.0` to absorb that rounding. If `r` is
+/// small enough that the chain collapses to the peak before reaching layer `1` (i.e. `common_area`
+/// can no longer fit under the remaining layers), the rest of the chain is left at `0.0` rather
+/// than dividing by it.
+///
+/// Returns the full chain together with the residual that is zero exactly when `r` is the
+/// correct tail cut-off for `LAYERS` equal-area layers.
+fn chain(r: f64) -> ([f64; LAYERS + 1], f64) {
+    let area = common_area(r);
+    let mut x = [0.0f64; LAYERS + 1];
+    x[LAYERS] = r;
+    for i in (1..LAYERS).rev() {
+        if x[i + 1] == 0.0 {
+            break;
+        }
+        let f_i = kernel(x[i + 1]);
+        let arg = (f_i + area / x[i + 1]).min(1.0);
+        x[i] = (-2.0 * arg.ln()).sqrt();
+    }
+    let residual = x[1] * (1.0 - kernel(x[1])) - area;
+    (x, residual)
+}
+
+/// Bisects `chain`'s residual to find the tail cut-off for which all `LAYERS` layers have equal
+/// area. `chain` is monotonically increasing in `r` (too-small an `r` leaves no room for the
+/// inner layers and drives the residual negative; too-large an `r` overshoots it), so ordinary
+/// bisection brackets the root without ever evaluating a `NaN` residual.
+fn solve_cutoff() -> f64 {
+    let mut lo = 0.5f64;
+    let mut hi = 5.0f64;
+    for _ in 0..200 {
+        let mid = 0.5 * (lo + hi);
+        let (_, residual) = chain(mid);
+        if residual < 0.0 {
+            lo = mid;
+        } else {
+            hi = mid;
+        }
+    }
+    0.5 * (lo + hi)
+}
+
+struct ZigguratTables {
+    /// `x[0]` is the tail cut-off `r`; `x[LAYERS]` is `0.0`, the peak.
+    x: [f64; LAYERS + 1],
+    /// `f[i] == exp(-x[i] * x[i] / 2)`.
+    f: [f64; LAYERS + 1],
+    /// Virtual width of the base strip, `common_area(r) / f(r)`. The base strip isn't a plain
+    /// rectangle like the other layers: its body is the rectangle `[0, r) x [0, f(r))`, entirely
+    /// under the curve since `f` is decreasing, stacked with the true Gaussian tail beyond `r`.
+    /// Scaling the uniform draw by this virtual width (rather than by `x[0] == r` itself) gives
+    /// the body and the tail their correct relative share of the strip's probability mass.
+    base_scale: f64,
+}
+
+fn tables() -> &'static ZigguratTables {
+    static TABLES: OnceLock<ZigguratTables> = OnceLock::new();
+    TABLES.get_or_init(|| {
+        let r = solve_cutoff();
+        let (peak_to_tail, _) = chain(r);
+
+        let mut x = [0.0f64; LAYERS + 1];
+        let mut f = [0.0f64; LAYERS + 1];
+        for i in 0..=LAYERS {
+            x[i] = peak_to_tail[LAYERS - i];
+            f[i] = kernel(x[i]);
+        }
+
+        debug_assert!(
+            x.windows(2).all(|w| w[0] > w[1] && !w[1].is_nan()),
+            "ziggurat table is not strictly decreasing (or contains NaN): {x:?}"
+        );
+
+        let base_scale = common_area(r) / kernel(r);
+        ZigguratTables { x, f, base_scale }
+    })
+}
+
+fn uniform_open01(csprng: &mut CsprngMut<'_, '_>) -> f64 {
+    let mut bytes = [0u8; 8];
+    loop {
+        csprng.next_bytes(&mut bytes);
+        // 53 bits of mantissa, scaled into (0, 1).
+        let mantissa = u64::from_le_bytes(bytes) >> 11;
+        if mantissa != 0 {
+            return (mantissa as f64) / ((1u64 << 53) as f64);
+        }
+    }
+}
+
+/// Draws one sample from the standard normal distribution `N(0, 1)` using the ziggurat method.
+fn standard_normal(csprng: &mut CsprngMut<'_, '_>) -> f64 {
+    let tables = tables();
+
+    loop {
+        let mut word_bytes = [0u8; 8];
+        csprng.next_bytes(&mut word_bytes);
+        let word = u64::from_le_bytes(word_bytes);
+
+        let i = (word & 0xff) as usize;
+        let sign = if (word >> 8) & 1 == 0 { 1.0 } else { -1.0 };
+        // The remaining 55 bits serve as the uniform fraction used to place the draw inside
+        // layer `i`.
+        let u = ((word >> 9) as f64) / ((1u64 << 55) as f64);
+
+        if i == 0 {
+            // Base strip: a candidate below `r` falls in the rectangle body, which is guaranteed
+            // under the curve, so it's accepted unconditionally. Otherwise resample from the
+            // exponential tail until the rejection test passes.
+            let candidate = u * tables.base_scale;
+            if candidate < tables.x[0] {
+                return sign * candidate;
+            }
+            loop {
+                let u1 = uniform_open01(csprng);
+                let u2 = uniform_open01(csprng);
+                let tail_x = -u1.ln() / tables.x[0];
+                let tail_y = -u2.ln();
+                if tail_y + tail_y > tail_x * tail_x {
+                    return sign * (tables.x[0] + tail_x);
+                }
+            }
+        }
+
+        let x = u * tables.x[i];
+
+        if u < tables.x[i + 1] / tables.x[i] {
+            return sign * x;
+        }
+
+        let wedge = tables.f[i] + u * (tables.f[i - 1] - tables.f[i]);
+        if wedge < kernel(x) {
+            return sign * x;
+        }
+        // Rejected: loop and draw again.
+    }
+}
+
+/// Draws one `f64` sample from `N(0, sigma * sigma)`.
+pub fn gaussian_f64(csprng: &mut CsprngMut<'_, '_>, sigma: f64) -> f64 {
+    standard_normal(csprng) * sigma
+}
+
+/// Draws one sample from `N(0, sigma * sigma)`, rounded to the nearest integer, for LWE-style
+/// discretized noise.
+pub fn gaussian_i64(csprng: &mut CsprngMut<'_, '_>, sigma: f64) -> i64 {
+    gaussian_f64(csprng, sigma).round() as i64
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::c_api::types::tests::to_generic;
+    use concrete_csprng::generators::SoftwareRandomGenerator;
+    use concrete_csprng::seeders::Seed;
+
+    #[test]
+    fn empirical_mean_and_variance_match_sigma() {
+        const SIGMA: f64 = 3.2;
+        const SAMPLES: usize = 200_000;
+
+        let mut generator = SoftwareRandomGenerator::new(Seed(42));
+        let mut csprng = to_generic(&mut generator);
+
+        let mut sum = 0.0;
+        let mut sum_sq = 0.0;
+        for _ in 0..SAMPLES {
+            let sample = gaussian_f64(&mut csprng, SIGMA);
+            sum += sample;
+            sum_sq += sample * sample;
+        }
+
+        let mean = sum / SAMPLES as f64;
+        let variance = sum_sq / SAMPLES as f64 - mean * mean;
+
+        assert!(mean.abs() < 0.05 * SIGMA, "mean = {mean}");
+        let relative_variance_error = (variance / (SIGMA * SIGMA) - 1.0).abs();
+        assert!(
+            relative_variance_error < 0.02,
+            "variance = {variance}, expected close to {}",
+            SIGMA * SIGMA
+        );
+    }
+
+    #[test]
+    fn discretized_samples_are_integers_with_matching_scale() {
+        const SIGMA: f64 = 100.0;
+        const SAMPLES: usize = 100_000;
+
+        let mut generator = SoftwareRandomGenerator::new(Seed(7));
+        let mut csprng = to_generic(&mut generator);
+
+        let mut sum_sq = 0i128;
+        for _ in 0..SAMPLES {
+            let sample = gaussian_i64(&mut csprng, SIGMA);
+            sum_sq += (sample as i128) * (sample as i128);
+        }
+
+        let variance = sum_sq as f64 / SAMPLES as f64;
+        let relative_variance_error = (variance / (SIGMA * SIGMA) - 1.0).abs();
+        assert!(
+            relative_variance_error < 0.02,
+            "variance = {variance}, expected close to {}",
+            SIGMA * SIGMA
+        );
+    }
+
+    #[test]
+    fn tail_mass_matches_normal_distribution() {
+        // P(|Z| >= 3) for the standard normal, via the same erfc approximation used to build the
+        // tables: a broken ziggurat tail (e.g. forcing every base-strip draw into the tail) would
+        // be off by several times this, not by a rounding error.
+        let expected = erfc(3.0 / core::f64::consts::SQRT_2);
+
+        const SAMPLES: usize = 1_000_000;
+        let mut generator = SoftwareRandomGenerator::new(Seed(11));
+        let mut csprng = to_generic(&mut generator);
+
+        let beyond_3 = (0..SAMPLES)
+            .filter(|_| standard_normal(&mut csprng).abs() >= 3.0)
+            .count();
+        let observed = beyond_3 as f64 / SAMPLES as f64;
+
+        assert!(
+            (observed - expected).abs() < 0.001,
+            "observed P(|Z| >= 3) = {observed}, expected ~{expected}"
+        );
+    }
+}
@@ -0,0 +1,5 @@
+pub mod bounded_uniform;
+pub mod buffered_csprng;
+pub mod forkable_csprng;
+pub mod gaussian;
+pub mod types;
@@ -0,0 +1,46 @@
+use crate::c_api::types::{Csprng, CsprngVtable, Uint128};
+
+/// A type-erased, borrowed handle to a C-side [`Csprng`], paired with the vtable that knows how
+/// to drive it.
+///
+/// This is the Rust-side counterpart of the `Csprng` / `CsprngVtable` pair exposed through the C
+/// API: it lets the implementation call into whichever generator the caller wired up (software,
+/// OS-backed, buffered, forked, ...) without knowing its concrete type.
+pub struct CsprngMut<'csprng, 'vtable> {
+    csprng: *mut Csprng,
+    vtable: &'vtable CsprngVtable,
+    _marker: core::marker::PhantomData<&'csprng mut Csprng>,
+}
+
+impl<'csprng, 'vtable> CsprngMut<'csprng, 'vtable> {
+    /// Creates a new handle from a raw `Csprng` pointer and the vtable used to drive it.
+    ///
+    /// # Safety
+    ///
+    /// `csprng` must be a valid pointer for the lifetime `'csprng`, and must be a valid receiver
+    /// for every function in `vtable`.
+    pub unsafe fn new(csprng: *mut Csprng, vtable: &'vtable CsprngVtable) -> Self {
+        Self {
+            csprng,
+            vtable,
+            _marker: core::marker::PhantomData,
+        }
+    }
+
+    /// Returns the number of remaining bytes that this generator can produce.
+    pub fn remaining_bytes(&self) -> Uint128 {
+        unsafe { (self.vtable.remaining_bytes)(self.csprng) }
+    }
+
+    /// Fills `bytes` with random data, returning the number of bytes actually written.
+    pub fn next_bytes(&mut self, bytes: &mut [u8]) -> usize {
+        unsafe { (self.vtable.next_bytes)(self.csprng, bytes.as_mut_ptr(), bytes.len()) }
+    }
+
+    /// Decomposes this handle into its raw `Csprng` pointer and vtable pointer, discarding the
+    /// borrow. Used by adaptors (e.g. [`crate::implementation::buffered_csprng::BufferedCsprng`])
+    /// that need to store a generator without carrying its lifetime.
+    pub(crate) fn into_raw_parts(self) -> (*mut Csprng, *const CsprngVtable) {
+        (self.csprng, self.vtable as *const CsprngVtable)
+    }
+}
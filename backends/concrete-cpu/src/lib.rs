@@ -0,0 +1,2 @@
+pub mod c_api;
+pub mod implementation;